@@ -0,0 +1,438 @@
+// Board rules shared by every front end. Nothing here knows about druid, crossterm, or any
+// other rendering toolkit - front ends read state out through `Grid::view`, `Index<GridPos>`,
+// and the accessor methods below.
+
+use std::ops::{Index, IndexMut};
+use std::sync::Arc;
+
+use rand::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct GridPos {
+    pub(crate) row: usize,
+    pub(crate) col: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum CellState {
+    SecretSafe,
+    SecretBomb,
+    Flagged,
+    Opened,
+    ExplodedSafe,
+    ExplodedBomb,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum GameOver {
+    Loss,
+    Win,
+    Ongoing,
+}
+
+// Plain, front-end-agnostic description of a cell: what to fill it with, and the
+// neighbor-bomb number to draw on top of it, if any.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum DisplayFill {
+    Hidden,
+    Flagged,
+    Opened,
+    Exploded,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CellView {
+    pub(crate) pos: GridPos,
+    pub(crate) fill: DisplayFill,
+    pub(crate) number: Option<u8>,
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) struct Grid {
+    storage: Arc<Vec<CellState>>,
+    height: usize,
+    width: usize,
+    fraction: f64,
+    torus: bool,
+}
+
+impl Index<GridPos> for Grid {
+    type Output = CellState;
+    fn index(&self, pos: GridPos) -> &Self::Output {
+        let idx = pos.row * self.width + pos.col;
+        &self.storage[idx]
+    }
+}
+
+impl IndexMut<GridPos> for Grid {
+    fn index_mut(&mut self, pos: GridPos) -> &mut Self::Output {
+        let idx = pos.row * self.width + pos.col;
+        // Arc is just for cheaper comparisons
+        Arc::make_mut(&mut self.storage).index_mut(idx)
+    }
+}
+
+impl Grid {
+    pub(crate) fn new(height: usize, width: usize, fraction: f64, torus: bool) -> Self {
+        let mut grid = Self {
+            storage: Arc::new(Vec::new()),
+            height: 0,
+            width: 0,
+            fraction,
+            torus,
+        };
+        grid.start(height, width, fraction, torus);
+        grid
+    }
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+    pub(crate) fn fraction(&self) -> f64 {
+        self.fraction
+    }
+    pub(crate) fn torus(&self) -> bool {
+        self.torus
+    }
+    pub(crate) fn iter_pos(&self) -> impl Iterator<Item = GridPos> + '_ {
+        (0..self.height).flat_map(|row| (0..self.width).map(move |col| GridPos { row, col }))
+    }
+    // Renderable snapshot of the board, with no knowledge of any particular front end.
+    pub(crate) fn view(&self) -> impl Iterator<Item = CellView> + '_ {
+        self.iter_pos().map(move |pos| {
+            let state = self[pos];
+            let fill = match state {
+                CellState::SecretSafe | CellState::SecretBomb => DisplayFill::Hidden,
+                CellState::Flagged => DisplayFill::Flagged,
+                CellState::Opened => DisplayFill::Opened,
+                CellState::ExplodedSafe | CellState::ExplodedBomb => DisplayFill::Exploded,
+            };
+            let n_bombs = self.n_bombs(pos);
+            let number = (state == CellState::Opened && n_bombs > 0).then_some(n_bombs as u8);
+            CellView { pos, fill, number }
+        })
+    }
+    pub(crate) fn neighbors(&self, pos: GridPos) -> [Option<GridPos>; 8] {
+        let above = self.above(pos);
+        let below = self.below(pos);
+        let left = self.left(pos);
+        let right = self.right(pos);
+        let above_left = above.and_then(|apos| self.left(apos));
+        let above_right = above.and_then(|apos| self.right(apos));
+        let below_left = below.and_then(|bpos| self.left(bpos));
+        let below_right = below.and_then(|bpos| self.right(bpos));
+        [
+            above,
+            below,
+            left,
+            right,
+            above_left,
+            above_right,
+            below_left,
+            below_right,
+        ]
+    }
+    // Number of neighboring unflagged bombs
+    pub(crate) fn n_bombs(&self, pos: GridPos) -> usize {
+        self.neighbors(pos)
+            .iter()
+            .filter(|m_neigh| {
+                m_neigh.map_or(false, |neighbor| {
+                    matches!(
+                        self[neighbor],
+                        CellState::SecretBomb | CellState::ExplodedBomb
+                    )
+                })
+            })
+            .count()
+    }
+    // Flood open. If cell is opened, and has n_bombs = 0, open all of its SecretSafe neighbors.
+    fn flood(&mut self, pos: GridPos) {
+        let mut to_flood = match self[pos] {
+            CellState::Opened => vec![pos],
+            CellState::Flagged => self
+                .neighbors(pos)
+                .iter()
+                .filter_map(|p| *p)
+                .filter(|p| self[*p] == CellState::Opened)
+                .collect(),
+            _ => unreachable!(),
+        };
+        while let Some(center) = to_flood.pop() {
+            assert_eq!(self[center], CellState::Opened);
+            if self.n_bombs(center) == 0 {
+                for neighbor in self.neighbors(center).into_iter().flatten() {
+                    match self[neighbor] {
+                        CellState::SecretSafe => {
+                            self[neighbor] = CellState::Opened;
+                            to_flood.push(neighbor);
+                        }
+                        CellState::Opened | CellState::Flagged => (),
+                        CellState::ExplodedSafe
+                        | CellState::ExplodedBomb
+                        | CellState::SecretBomb => unreachable!(),
+                    }
+                }
+            }
+        }
+    }
+    pub(crate) fn is_win(&self) -> bool {
+        self.iter_pos()
+            .all(|pos| matches!(self[pos], CellState::Opened | CellState::Flagged))
+    }
+    // Flag, return if exploded
+    pub(crate) fn flag(&mut self, pos: GridPos) -> bool {
+        match self[pos] {
+            CellState::SecretBomb => {
+                self[pos] = CellState::Flagged;
+                self.flood(pos);
+                false
+            }
+            CellState::SecretSafe => {
+                self[pos] = CellState::ExplodedSafe;
+                true
+            }
+            _ => false,
+        }
+    }
+    // Open, return if exploded
+    pub(crate) fn open(&mut self, pos: GridPos) -> bool {
+        match self[pos] {
+            CellState::SecretBomb => {
+                self[pos] = CellState::ExplodedBomb;
+                true
+            }
+            CellState::SecretSafe => {
+                self[pos] = CellState::Opened;
+                self.flood(pos);
+                false
+            }
+            _ => false,
+        }
+    }
+    // Start/restart, optionally reshaping the board. Randomize bombs, pick random 0 and open it.
+    pub(crate) fn start(&mut self, height: usize, width: usize, fraction: f64, torus: bool) {
+        self.height = height;
+        self.width = width;
+        self.fraction = fraction;
+        self.torus = torus;
+        self.storage = Arc::new(vec![CellState::ExplodedSafe; height * width]);
+        // Allow seeding?
+        let mut rng = thread_rng();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let pos = GridPos { row, col };
+                let cell_state = if rng.gen::<f64>() < self.fraction {
+                    CellState::SecretBomb
+                } else {
+                    CellState::SecretSafe
+                };
+                self[pos] = cell_state;
+            }
+        }
+        let zero_positions: Vec<GridPos> = self
+            .iter_pos()
+            .filter(|&pos| self[pos] == CellState::SecretSafe && self.n_bombs(pos) == 0)
+            .collect();
+        // Zero_positions could be empty, so we have a fallback
+        if !zero_positions.is_empty() {
+            let index = rng.gen_range(0..zero_positions.len());
+            let pos = zero_positions[index];
+            let exploded = self.open(pos);
+            assert!(!exploded);
+        } else {
+            let pos = GridPos {
+                row: rng.gen_range(0..self.height),
+                col: rng.gen_range(0..self.width),
+            };
+            self[pos] = CellState::SecretSafe;
+            for neighbor in self.neighbors(pos) {
+                if let Some(n_pos) = neighbor {
+                    self[n_pos] = CellState::SecretSafe;
+                }
+            }
+            let exploded = self.open(pos);
+            assert!(!exploded);
+        }
+    }
+    // Total bombs placed at game start, including those now flagged or exploded
+    pub(crate) fn total_bombs(&self) -> usize {
+        self.iter_pos()
+            .filter(|&pos| {
+                matches!(
+                    self[pos],
+                    CellState::SecretBomb | CellState::Flagged | CellState::ExplodedBomb
+                )
+            })
+            .count()
+    }
+    pub(crate) fn flagged_count(&self) -> usize {
+        self.iter_pos()
+            .filter(|&pos| self[pos] == CellState::Flagged)
+            .count()
+    }
+    // Chord: for an Opened cell with every neighboring bomb flagged (`n_bombs` is the count of
+    // still-unflagged bomb neighbors, so 0 means none remain), open every remaining hidden
+    // neighbor at once. Flagging a safe cell explodes it immediately, so every `Flagged` cell is
+    // a real bomb - once `n_bombs` hits 0 there's no live bomb left to open. Returns whether any
+    // opened neighbor exploded (always false, kept for symmetry with `flag`/`open`).
+    pub(crate) fn chord(&mut self, pos: GridPos) -> bool {
+        if self[pos] != CellState::Opened || self.n_bombs(pos) != 0 {
+            return false;
+        }
+        let mut exploded = false;
+        for neighbor in self.neighbors(pos).into_iter().flatten() {
+            if self[neighbor] == CellState::SecretSafe && self.open(neighbor) {
+                exploded = true;
+            }
+        }
+        exploded
+    }
+    // Turn any explosions back into secret
+    pub(crate) fn clear_explosions(&mut self) {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let pos = GridPos { row, col };
+                let new_state = match self[pos] {
+                    CellState::ExplodedBomb => CellState::SecretBomb,
+                    CellState::ExplodedSafe => CellState::SecretSafe,
+                    _ => continue,
+                };
+                self[pos] = new_state;
+            }
+        }
+    }
+    fn above(&self, pos: GridPos) -> Option<GridPos> {
+        if self.torus {
+            let row = (pos.row + self.height - 1) % self.height;
+            Some(GridPos { row, col: pos.col })
+        } else {
+            pos.row
+                .checked_sub(1)
+                .map(|row| GridPos { row, col: pos.col })
+        }
+    }
+    fn below(&self, pos: GridPos) -> Option<GridPos> {
+        if self.torus {
+            let row = (pos.row + 1) % self.height;
+            Some(GridPos { row, col: pos.col })
+        } else {
+            (pos.row < self.height - 1).then_some(GridPos {
+                row: pos.row + 1,
+                col: pos.col,
+            })
+        }
+    }
+    fn left(&self, pos: GridPos) -> Option<GridPos> {
+        if self.torus {
+            let col = (pos.col + self.width - 1) % self.width;
+            Some(GridPos { row: pos.row, col })
+        } else {
+            pos.col
+                .checked_sub(1)
+                .map(|col| GridPos { row: pos.row, col })
+        }
+    }
+    fn right(&self, pos: GridPos) -> Option<GridPos> {
+        if self.torus {
+            let col = (pos.col + 1) % self.width;
+            Some(GridPos { row: pos.row, col })
+        } else {
+            (pos.col < self.width - 1).then_some(GridPos {
+                row: pos.row,
+                col: pos.col + 1,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a bomb-free grid, then seeds bombs at the given positions.
+    fn grid_with_bombs(height: usize, width: usize, bombs: &[(usize, usize)]) -> Grid {
+        let mut grid = Grid {
+            storage: Arc::new(vec![CellState::SecretSafe; height * width]),
+            height,
+            width,
+            fraction: 0.0,
+            torus: false,
+        };
+        for &(row, col) in bombs {
+            grid[GridPos { row, col }] = CellState::SecretBomb;
+        }
+        grid
+    }
+
+    #[test]
+    fn chord_opens_remaining_neighbors_when_satisfied() {
+        // No bombs at all: the opened cell already shows 0, so chording is immediately
+        // satisfied (0 flagged == 0 unflagged bombs) and should sweep every hidden neighbor.
+        let mut grid = grid_with_bombs(3, 3, &[]);
+        let center = GridPos { row: 1, col: 1 };
+        grid[center] = CellState::Opened;
+
+        assert!(!grid.chord(center));
+        for row in 0..3 {
+            for col in 0..3 {
+                let pos = GridPos { row, col };
+                if pos != center {
+                    assert_eq!(grid[pos], CellState::Opened);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chord_does_nothing_when_unsatisfied() {
+        // One unflagged bomb neighbor: n_bombs is 1 but 0 neighbors are flagged, so chording
+        // must leave every neighbor untouched.
+        let mut grid = grid_with_bombs(3, 3, &[(0, 0)]);
+        let center = GridPos { row: 1, col: 1 };
+        grid[center] = CellState::Opened;
+
+        assert!(!grid.chord(center));
+        assert_eq!(grid[GridPos { row: 0, col: 0 }], CellState::SecretBomb);
+        assert_eq!(grid[GridPos { row: 0, col: 1 }], CellState::SecretSafe);
+    }
+
+    #[test]
+    fn chord_does_nothing_with_an_unflagged_bomb_remaining() {
+        // Two bomb neighbors, only one flagged: one unflagged bomb remains (`n_bombs` is 1),
+        // so chording must not fire - it must never open a live, unflagged bomb.
+        let mut grid = grid_with_bombs(3, 3, &[(0, 0), (0, 1)]);
+        grid[GridPos { row: 0, col: 0 }] = CellState::Flagged;
+        let center = GridPos { row: 1, col: 1 };
+        grid[center] = CellState::Opened;
+
+        assert!(!grid.chord(center));
+        assert_eq!(grid[GridPos { row: 0, col: 0 }], CellState::Flagged);
+        assert_eq!(grid[GridPos { row: 0, col: 1 }], CellState::SecretBomb);
+    }
+
+    #[test]
+    fn chord_opens_safe_neighbors_once_fully_flagged() {
+        // Both bomb neighbors flagged: `n_bombs` is 0, so chording sweeps the remaining
+        // hidden (safe) neighbors without ever touching a bomb.
+        let mut grid = grid_with_bombs(3, 3, &[(0, 0), (0, 1)]);
+        grid[GridPos { row: 0, col: 0 }] = CellState::Flagged;
+        grid[GridPos { row: 0, col: 1 }] = CellState::Flagged;
+        let center = GridPos { row: 1, col: 1 };
+        grid[center] = CellState::Opened;
+
+        assert!(!grid.chord(center));
+        for row in 0..3 {
+            for col in 0..3 {
+                let pos = GridPos { row, col };
+                if pos == GridPos { row: 0, col: 0 } || pos == GridPos { row: 0, col: 1 } {
+                    assert_eq!(grid[pos], CellState::Flagged);
+                } else if pos != center {
+                    assert_eq!(grid[pos], CellState::Opened);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,738 @@
+// druid front end. All game rules live in `crate::game`; this module only renders that state
+// and turns input events into calls against it.
+
+use druid::kurbo::{BezPath, Circle, Line};
+use druid::piet::{FontFamily, Text, TextLayout, TextLayoutBuilder};
+use druid::widget::prelude::*;
+use druid::widget::{Checkbox, Flex, Label, Slider};
+use druid::{
+    AppLauncher, Color, Data, Lens, MouseButton, Point, Rect, Size, TimerToken, WidgetExt,
+    WindowDesc,
+};
+
+use std::time::{Duration, Instant};
+
+use crate::game::{CellState, GameOver, Grid, GridPos};
+
+impl Data for Grid {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Data for GameOver {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+const NUM_FONT_SIZE: f64 = 36.0;
+const SHRINK_CELL_SIZE: f64 = 40.0;
+const SPACING: f64 = 5.0;
+const MAX_ASPECT: f64 = 1.15;
+const TORUS_OVERLAP: usize = 3;
+
+const PINK: Color = Color::rgb8(0xff, 0xb7, 0xc5);
+const BACKGROUND: Color = Color::grey8(23);
+const READOUT_COLOR: Color = Color::rgb8(0xff, 0x30, 0x30);
+const HOVER_TINT: Color = Color::rgba8(0xff, 0xff, 0xff, 0x60);
+const HOVER_NEIGHBOR_TINT: Color = Color::rgba8(0xff, 0xff, 0xff, 0x28);
+
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+const DIGIT_ASPECT: f64 = 0.55;
+const SEGMENT_THICKNESS: f64 = 0.15;
+const MIN_GRID_DIM: f64 = 4.0;
+const MAX_GRID_DIM: f64 = 40.0;
+
+// Segment order: a=top, b=top-right, c=bottom-right, d=bottom, e=bottom-left, f=top-left, g=middle
+#[rustfmt::skip]
+const DIGIT_SEGMENTS: [[bool; 7]; 10] = [
+    [true,  true,  true,  true,  true,  true,  false], // 0
+    [false, true,  true,  false, false, false, false], // 1
+    [true,  true,  false, true,  true,  false, true ], // 2
+    [true,  true,  true,  true,  false, false, true ], // 3
+    [false, true,  true,  false, false, true,  true ], // 4
+    [true,  false, true,  true,  false, true,  true ], // 5
+    [true,  false, true,  true,  true,  true,  true ], // 6
+    [true,  true,  true,  false, false, false, false], // 7
+    [true,  true,  true,  true,  true,  true,  true ], // 8
+    [true,  true,  true,  true,  false, true,  true ], // 9
+];
+
+// Draws a single seven-segment digit inside `rect`, scaling segment thickness to its height.
+fn draw_digit(ctx: &mut PaintCtx, rect: Rect, digit: usize, color: &Color) {
+    let lit = DIGIT_SEGMENTS[digit];
+    let w = rect.width();
+    let h = rect.height();
+    let t = h * SEGMENT_THICKNESS;
+    let x0 = rect.x0;
+    let y0 = rect.y0;
+    let mid_y = y0 + h / 2.0;
+    let segments = [
+        Rect::new(x0 + t, y0, x0 + w - t, y0 + t),                     // a: top
+        Rect::new(x0 + w - t, y0 + t, x0 + w, mid_y),                  // b: top-right
+        Rect::new(x0 + w - t, mid_y, x0 + w, y0 + h - t),              // c: bottom-right
+        Rect::new(x0 + t, y0 + h - t, x0 + w - t, y0 + h),             // d: bottom
+        Rect::new(x0, mid_y, x0 + t, y0 + h - t),                      // e: bottom-left
+        Rect::new(x0, y0 + t, x0 + t, mid_y),                          // f: top-left
+        Rect::new(x0 + t, mid_y - t / 2.0, x0 + w - t, mid_y + t / 2.0), // g: middle
+    ];
+    for (on, segment) in lit.into_iter().zip(segments) {
+        if on {
+            ctx.fill(segment.to_rounded_rect(t / 2.0), color);
+        }
+    }
+}
+
+// A row of fixed-width seven-segment digits, reading some `usize` out of `AppData`.
+struct SevenSegmentDisplay {
+    digits: usize,
+    value: fn(&AppData) -> usize,
+}
+
+impl Widget<AppData> for SevenSegmentDisplay {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut AppData, _env: &Env) {}
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        _event: &LifeCycle,
+        _data: &AppData,
+        _env: &Env,
+    ) {
+    }
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &AppData, data: &AppData, _env: &Env) {
+        if (self.value)(old_data) != (self.value)(data) {
+            ctx.request_paint();
+        }
+    }
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &AppData,
+        _env: &Env,
+    ) -> Size {
+        let height = bc.max().height.min(SHRINK_CELL_SIZE);
+        let width = height * DIGIT_ASPECT * self.digits as f64;
+        bc.constrain(Size { width, height })
+    }
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &AppData, _env: &Env) {
+        let max_value = 10_usize.pow(self.digits as u32) - 1;
+        let value = (self.value)(data).min(max_value);
+        let size = ctx.size();
+        let digit_width = size.width / self.digits as f64;
+        let text = format!("{value:0width$}", width = self.digits);
+        for (i, ch) in text.chars().enumerate() {
+            let digit = ch.to_digit(10).expect("formatted digit") as usize;
+            let rect = Rect::from_origin_size(
+                Point {
+                    x: digit_width * i as f64,
+                    y: 0.0,
+                },
+                Size {
+                    width: digit_width - SPACING,
+                    height: size.height,
+                },
+            );
+            draw_digit(ctx, rect, digit, &READOUT_COLOR);
+        }
+    }
+}
+
+// Elapsed game time. Equality ignores `start`, so setting it on the first click doesn't
+// trigger an `update`; each tick that changes `elapsed_secs` does, which repaints the timer.
+#[derive(Clone, Copy)]
+struct ElapsedClock {
+    start: Option<Instant>,
+    elapsed_secs: u64,
+}
+
+impl ElapsedClock {
+    fn new() -> Self {
+        Self {
+            start: None,
+            elapsed_secs: 0,
+        }
+    }
+}
+
+impl Data for ElapsedClock {
+    fn same(&self, other: &Self) -> bool {
+        self.elapsed_secs == other.elapsed_secs
+    }
+}
+
+fn draw_mouth(ctx: &mut PaintCtx, center: Point, radius: f64, curvature: f64, color: &Color) {
+    let half_width = radius * 0.5;
+    let y = center.y + radius * 0.35;
+    let start = Point {
+        x: center.x - half_width,
+        y,
+    };
+    let end = Point {
+        x: center.x + half_width,
+        y,
+    };
+    let control = Point {
+        x: center.x,
+        y: y + curvature * radius * 0.4,
+    };
+    let mut path = BezPath::new();
+    path.move_to(start);
+    path.quad_to(control, end);
+    ctx.stroke(path, color, 2.0);
+}
+
+fn draw_x(ctx: &mut PaintCtx, center: Point, half_size: f64, color: &Color) {
+    ctx.stroke(
+        Line::new(
+            Point {
+                x: center.x - half_size,
+                y: center.y - half_size,
+            },
+            Point {
+                x: center.x + half_size,
+                y: center.y + half_size,
+            },
+        ),
+        color,
+        2.0,
+    );
+    ctx.stroke(
+        Line::new(
+            Point {
+                x: center.x - half_size,
+                y: center.y + half_size,
+            },
+            Point {
+                x: center.x + half_size,
+                y: center.y - half_size,
+            },
+        ),
+        color,
+        2.0,
+    );
+}
+
+// Clickable face that mirrors game state: neutral while playing, surprised while a mouse
+// button is held over the grid, and a win/loss face once the round ends.
+struct SmileyWidget;
+
+impl Widget<AppData> for SmileyWidget {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut AppData, _env: &Env) {}
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        _event: &LifeCycle,
+        _data: &AppData,
+        _env: &Env,
+    ) {
+    }
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &AppData, data: &AppData, _env: &Env) {
+        if old_data.game_over != data.game_over || old_data.mouse_down != data.mouse_down {
+            ctx.request_paint();
+        }
+    }
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &AppData,
+        _env: &Env,
+    ) -> Size {
+        let side = bc.max().width.min(bc.max().height).min(SHRINK_CELL_SIZE);
+        bc.constrain(Size {
+            width: side,
+            height: side,
+        })
+    }
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &AppData, _env: &Env) {
+        let size = ctx.size();
+        let center = Point {
+            x: size.width / 2.0,
+            y: size.height / 2.0,
+        };
+        let radius = size.width.min(size.height) / 2.0 - 2.0;
+        ctx.stroke(Circle::new(center, radius), &Color::WHITE, 2.0);
+
+        let eye_dx = radius * 0.4;
+        let eye_dy = radius * 0.25;
+        let left_eye = Point {
+            x: center.x - eye_dx,
+            y: center.y - eye_dy,
+        };
+        let right_eye = Point {
+            x: center.x + eye_dx,
+            y: center.y - eye_dy,
+        };
+        let eye_radius = radius * 0.12;
+
+        let surprised = data.mouse_down && data.game_over == GameOver::Ongoing;
+        if surprised {
+            ctx.fill(Circle::new(left_eye, eye_radius), &Color::WHITE);
+            ctx.fill(Circle::new(right_eye, eye_radius), &Color::WHITE);
+            let mouth_center = Point {
+                x: center.x,
+                y: center.y + radius * 0.35,
+            };
+            ctx.stroke(
+                Circle::new(mouth_center, radius * 0.15),
+                &Color::WHITE,
+                2.0,
+            );
+        } else {
+            match data.game_over {
+                GameOver::Loss => {
+                    draw_x(ctx, left_eye, eye_radius, &Color::WHITE);
+                    draw_x(ctx, right_eye, eye_radius, &Color::WHITE);
+                    draw_mouth(ctx, center, radius, -1.0, &Color::WHITE);
+                }
+                GameOver::Win => {
+                    ctx.fill(Circle::new(left_eye, eye_radius), &Color::WHITE);
+                    ctx.fill(Circle::new(right_eye, eye_radius), &Color::WHITE);
+                    draw_mouth(ctx, center, radius, 1.0, &Color::WHITE);
+                }
+                GameOver::Ongoing => {
+                    ctx.fill(Circle::new(left_eye, eye_radius), &Color::WHITE);
+                    ctx.fill(Circle::new(right_eye, eye_radius), &Color::WHITE);
+                    draw_mouth(ctx, center, radius, 0.0, &Color::WHITE);
+                }
+            }
+        }
+    }
+}
+
+// Board parameters edited via the settings row; applied to `grid` on Restart rather than
+// taking effect immediately, so mid-game reshaping never happens.
+#[derive(Clone, Data, Lens)]
+struct PendingSettings {
+    height: f64,
+    width: f64,
+    fraction: f64,
+    torus: bool,
+    easy: bool,
+}
+
+impl PendingSettings {
+    fn new(height: usize, width: usize, fraction: f64, torus: bool, easy: bool) -> Self {
+        Self {
+            height: height as f64,
+            width: width as f64,
+            fraction,
+            torus,
+            easy,
+        }
+    }
+}
+
+#[derive(Clone, Data, Lens)]
+struct AppData {
+    grid: Grid,
+    game_over: GameOver,
+    easy_mode: bool,
+    clock: ElapsedClock,
+    mouse_down: bool,
+    pending: PendingSettings,
+}
+
+fn remaining_mines(data: &AppData) -> usize {
+    data.grid
+        .total_bombs()
+        .saturating_sub(data.grid.flagged_count())
+}
+
+fn elapsed_seconds(data: &AppData) -> usize {
+    data.clock.elapsed_secs as usize
+}
+
+struct CleansweeperWidget {
+    cell_size: Size,
+    timer_id: TimerToken,
+    hover: Option<GridPos>,
+}
+impl CleansweeperWidget {
+    fn grid_pos(
+        &self,
+        p: Point,
+        grid_height: usize,
+        grid_width: usize,
+        is_torus: bool,
+    ) -> Option<GridPos> {
+        let w0 = self.cell_size.width;
+        let h0 = self.cell_size.height;
+        if p.x < 0.0 || p.y < 0.0 || w0 == 0.0 || h0 == 0.0 {
+            return None;
+        }
+        let row = (p.y / h0) as usize;
+        let col = (p.x / w0) as usize;
+        if row >= grid_height || col >= grid_width {
+            if is_torus {
+                if row < grid_height + TORUS_OVERLAP && col < grid_width + TORUS_OVERLAP {
+                    Some(GridPos {
+                        row: row % grid_height,
+                        col: col % grid_width,
+                    })
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        } else {
+            Some(GridPos { row, col })
+        }
+    }
+}
+
+impl Widget<AppData> for CleansweeperWidget {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut AppData, _env: &Env) {
+        match event {
+            Event::WindowConnected => ctx.request_paint(),
+            Event::Timer(token) => {
+                if *token == self.timer_id && data.game_over == GameOver::Ongoing {
+                    if let Some(start) = data.clock.start {
+                        data.clock.elapsed_secs = start.elapsed().as_secs();
+                    }
+                    self.timer_id = ctx.request_timer(TIMER_INTERVAL);
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseDown(e) => {
+                data.mouse_down = true;
+                ctx.set_active(true);
+                if data.game_over == GameOver::Ongoing {
+                    if data.clock.start.is_none() {
+                        data.clock.start = Some(Instant::now());
+                        self.timer_id = ctx.request_timer(TIMER_INTERVAL);
+                    }
+                    match e.button {
+                        MouseButton::Left => {
+                            let grid_pos_opt = self.grid_pos(
+                                e.pos,
+                                data.grid.height(),
+                                data.grid.width(),
+                                data.grid.torus(),
+                            );
+                            grid_pos_opt.inspect(|pos| {
+                                let exploded = data.grid.flag(*pos);
+                                if exploded {
+                                    data.game_over = GameOver::Loss;
+                                }
+                            });
+                        }
+                        MouseButton::Right => {
+                            let grid_pos_opt = self.grid_pos(
+                                e.pos,
+                                data.grid.height(),
+                                data.grid.width(),
+                                data.grid.torus(),
+                            );
+                            grid_pos_opt.inspect(|pos| {
+                                let exploded = data.grid.open(*pos);
+                                if exploded {
+                                    data.game_over = GameOver::Loss;
+                                }
+                            });
+                        }
+                        MouseButton::Middle => {
+                            // Chord: open every remaining neighbor of a satisfied numbered cell.
+                            let grid_pos_opt = self.grid_pos(
+                                e.pos,
+                                data.grid.height(),
+                                data.grid.width(),
+                                data.grid.torus(),
+                            );
+                            grid_pos_opt.inspect(|pos| {
+                                let exploded = data.grid.chord(*pos);
+                                if exploded {
+                                    data.game_over = GameOver::Loss;
+                                }
+                            });
+                        }
+                        _ => (),
+                    }
+                    if data.grid.is_win() {
+                        data.game_over = GameOver::Win;
+                    }
+                }
+            }
+            Event::MouseUp(_) => {
+                data.mouse_down = false;
+                ctx.set_active(false);
+            }
+            Event::MouseMove(e) => {
+                let hover =
+                    self.grid_pos(e.pos, data.grid.height(), data.grid.width(), data.grid.torus());
+                if hover != self.hover {
+                    self.hover = hover;
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseLeave(_) => {
+                if self.hover.is_some() {
+                    self.hover = None;
+                    ctx.request_paint();
+                }
+            }
+            _ => {}
+        }
+    }
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        _event: &LifeCycle,
+        _data: &AppData,
+        _env: &Env,
+    ) {
+    }
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &AppData, data: &AppData, _env: &Env) {
+        if data.grid != old_data.grid {
+            ctx.request_paint();
+        }
+    }
+    fn layout(
+        &mut self,
+        _layout_ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &AppData,
+        _env: &Env,
+    ) -> Size {
+        let Size {
+            height: max_height,
+            width: max_width,
+        } = bc.max();
+        let ideal_ratio = data.grid.height() as f64 / data.grid.width() as f64;
+        let height_cap = max_width * ideal_ratio * MAX_ASPECT;
+        let width_cap = (max_height / ideal_ratio) * MAX_ASPECT;
+        Size {
+            height: max_height.min(height_cap),
+            width: max_width.min(width_cap),
+        }
+    }
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &AppData, _env: &Env) {
+        let size: Size = ctx.size();
+        let visual_width = data.grid.width() + if data.grid.torus() { TORUS_OVERLAP } else { 0 };
+        let visual_height = data.grid.height() + if data.grid.torus() { TORUS_OVERLAP } else { 0 };
+        let w0 = size.width / visual_width as f64;
+        let h0 = size.height / visual_height as f64;
+        let cell_size = Size {
+            width: w0,
+            height: h0,
+        };
+        self.cell_size = cell_size;
+        let draw_size = Size {
+            width: w0 - 2.0,
+            height: h0 - 2.0,
+        };
+        let font_scale_down = ((w0.min(h0)) / SHRINK_CELL_SIZE).min(1.0);
+        let font_size = NUM_FONT_SIZE * font_scale_down;
+        for visual_row in 0..visual_height {
+            for visual_col in 0..visual_width {
+                let pos = GridPos {
+                    row: visual_row % data.grid.height(),
+                    col: visual_col % data.grid.width(),
+                };
+                let cell_state = data.grid[pos];
+                let point = Point {
+                    x: w0 * visual_col as f64 + 1.0,
+                    y: h0 * visual_row as f64 + 1.0,
+                };
+                // Unknown is dark grey fill
+                // Flagged is pink fill
+                // Opened is white fill
+                // Exploded is red fill
+                // Number of unflagged neighbors written on top of white fill,
+                // in varying colors. If none, no number.
+                let rect = Rect::from_origin_size(point, draw_size);
+                let fill_color = match cell_state {
+                    CellState::SecretSafe | CellState::SecretBomb => Color::GRAY,
+                    CellState::Flagged => PINK,
+                    CellState::Opened => Color::WHITE,
+                    CellState::ExplodedSafe | CellState::ExplodedBomb => Color::RED,
+                };
+                ctx.fill(rect, &fill_color);
+                if let Some(hover_pos) = self.hover {
+                    if pos == hover_pos {
+                        ctx.fill(rect, &HOVER_TINT);
+                    } else if data
+                        .grid
+                        .neighbors(hover_pos)
+                        .into_iter()
+                        .flatten()
+                        .any(|neighbor| neighbor == pos)
+                    {
+                        ctx.fill(rect, &HOVER_NEIGHBOR_TINT);
+                    }
+                }
+                if cell_state == CellState::Opened {
+                    let n_bombs = data.grid.n_bombs(pos);
+                    if n_bombs > 0 {
+                        let text_color = match n_bombs {
+                            1 => Color::BLUE,
+                            2 => Color::GREEN,
+                            3 => Color::MAROON,
+                            4 => Color::BLACK,
+                            5 => Color::PURPLE,
+                            6 => Color::AQUA,
+                            7 => Color::OLIVE,
+                            8 => Color::LIME,
+                            _ => unreachable!(),
+                        };
+                        let text_layout = ctx
+                            .text()
+                            .new_text_layout(format!("{n_bombs}"))
+                            .font(FontFamily::MONOSPACE, font_size)
+                            .text_color(text_color)
+                            .build()
+                            .expect("Text failed");
+                        let text_size = text_layout.size();
+                        let new_corner = Point {
+                            x: point.x + (w0 - text_size.width) / 2.0,
+                            y: point.y + (h0 - text_size.height) / 2.0,
+                        };
+                        ctx.draw_text(&text_layout, new_corner);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn make_widget() -> impl Widget<AppData> {
+    let cleansweeper = CleansweeperWidget {
+        cell_size: Size {
+            width: 0.0,
+            height: 0.0,
+        },
+        timer_id: TimerToken::INVALID,
+        hover: None,
+    };
+    let restart_button = SmileyWidget
+        .on_click(move |_ctx, data: &mut AppData, _env| {
+            data.game_over = GameOver::Ongoing;
+            data.clock = ElapsedClock::new();
+            data.mouse_down = false;
+            data.easy_mode = data.pending.easy;
+            data.grid.start(
+                data.pending.height as usize,
+                data.pending.width as usize,
+                data.pending.fraction,
+                data.pending.torus,
+            );
+        })
+        .center();
+    let timer_display = SevenSegmentDisplay {
+        digits: 3,
+        value: elapsed_seconds,
+    };
+    let mine_counter = SevenSegmentDisplay {
+        digits: 3,
+        value: remaining_mines,
+    };
+    let game_over_text = Label::new(|data: &AppData, _env: &_| match data.game_over {
+        GameOver::Loss => {
+            if data.easy_mode {
+                "Undo?"
+            } else {
+                "Try again?"
+            }
+        }
+        GameOver::Win => "You win!",
+        GameOver::Ongoing => "Good luck!",
+    })
+    .with_text_size(NUM_FONT_SIZE)
+    .on_click(move |_ctx, data: &mut AppData, _env| {
+        if data.easy_mode {
+            data.game_over = GameOver::Ongoing;
+            data.grid.clear_explosions();
+        }
+    })
+    .center()
+    .expand_width();
+    let bottom_row = Flex::row()
+        .with_child(timer_display)
+        .with_spacer(SPACING)
+        .with_flex_child(restart_button, 1.0)
+        .with_spacer(SPACING)
+        .with_flex_child(game_over_text, 1.0)
+        .with_spacer(SPACING)
+        .with_child(mine_counter);
+    let settings_row = make_settings_row();
+    Flex::column()
+        .with_flex_child(cleansweeper, 1.0)
+        .with_spacer(SPACING)
+        .with_child(bottom_row)
+        .with_spacer(SPACING)
+        .with_child(settings_row)
+        .with_spacer(SPACING)
+        .background(BACKGROUND)
+}
+
+// Sliders/toggles for the next round's board shape. Edits here only take effect once
+// Restart is clicked, via `PendingSettings` on `AppData`.
+fn make_settings_row() -> impl Widget<AppData> {
+    let height_label =
+        Label::new(|data: &AppData, _env: &_| format!("H {}", data.pending.height as usize));
+    let height_slider = Slider::new()
+        .with_range(MIN_GRID_DIM, MAX_GRID_DIM)
+        .with_step(1.0)
+        .lens(PendingSettings::height)
+        .lens(AppData::pending);
+    let width_label =
+        Label::new(|data: &AppData, _env: &_| format!("W {}", data.pending.width as usize));
+    let width_slider = Slider::new()
+        .with_range(MIN_GRID_DIM, MAX_GRID_DIM)
+        .with_step(1.0)
+        .lens(PendingSettings::width)
+        .lens(AppData::pending);
+    let fraction_label = Label::new(|data: &AppData, _env: &_| {
+        format!("Bombs {:.0}%", data.pending.fraction * 100.0)
+    });
+    let fraction_slider = Slider::new()
+        .with_range(0.0, 1.0)
+        .lens(PendingSettings::fraction)
+        .lens(AppData::pending);
+    let torus_toggle = Checkbox::new("Torus")
+        .lens(PendingSettings::torus)
+        .lens(AppData::pending);
+    let easy_toggle = Checkbox::new("Easy")
+        .lens(PendingSettings::easy)
+        .lens(AppData::pending);
+    Flex::row()
+        .with_child(height_label)
+        .with_child(height_slider)
+        .with_spacer(SPACING)
+        .with_child(width_label)
+        .with_child(width_slider)
+        .with_spacer(SPACING)
+        .with_child(fraction_label)
+        .with_child(fraction_slider)
+        .with_spacer(SPACING)
+        .with_child(torus_toggle)
+        .with_spacer(SPACING)
+        .with_child(easy_toggle)
+}
+
+pub(crate) fn run(height: usize, width: usize, fraction: f64, torus: bool, easy: bool) {
+    let window = WindowDesc::new(make_widget())
+        .window_size(Size {
+            width: 800.,
+            height: 800.,
+        })
+        .title("Cleansweeper");
+    let mut grid = Grid::new(height, width, fraction, torus);
+    grid.start(height, width, fraction, torus);
+    let pending = PendingSettings::new(height, width, fraction, torus, easy);
+
+    AppLauncher::with_window(window)
+        .log_to_console()
+        .launch(AppData {
+            grid,
+            game_over: GameOver::Ongoing,
+            easy_mode: easy,
+            clock: ElapsedClock::new(),
+            mouse_down: false,
+            pending,
+        })
+        .expect("launch failed");
+}
@@ -0,0 +1,204 @@
+// Headless-friendly terminal front end, built on the same `crate::game` rules as the druid GUI.
+// Arrow keys/WASD move a cursor; Enter/Space opens and `f` flags it. The mouse mirrors the GUI's
+// (inverted) scheme: left-click flags, right-click opens.
+
+use std::io::{self, Write};
+
+use crossterm::event::{
+    self, Event as CEvent, KeyCode, KeyEvent, MouseButton as CMouseButton, MouseEvent,
+    MouseEventKind,
+};
+use crossterm::style::{Color as CColor, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute};
+
+use crate::game::{DisplayFill, GameOver, Grid, GridPos};
+
+const CELL_WIDTH: u16 = 2;
+
+pub(crate) fn run(height: usize, width: usize, fraction: f64, torus: bool, easy: bool) {
+    if let Err(err) = run_inner(height, width, fraction, torus, easy) {
+        eprintln!("terminal front end failed: {err}");
+    }
+}
+
+fn run_inner(height: usize, width: usize, fraction: f64, torus: bool, easy: bool) -> io::Result<()> {
+    let mut grid = Grid::new(height, width, fraction, torus);
+    let mut game_over = GameOver::Ongoing;
+    let mut cursor_pos = GridPos { row: 0, col: 0 };
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(
+        stdout,
+        terminal::EnterAlternateScreen,
+        event::EnableMouseCapture,
+        cursor::Hide
+    )?;
+
+    let result = game_loop(&mut stdout, &mut grid, &mut game_over, &mut cursor_pos, easy);
+
+    execute!(
+        stdout,
+        event::DisableMouseCapture,
+        cursor::Show,
+        terminal::LeaveAlternateScreen
+    )?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn game_loop(
+    stdout: &mut impl Write,
+    grid: &mut Grid,
+    game_over: &mut GameOver,
+    cursor_pos: &mut GridPos,
+    easy: bool,
+) -> io::Result<()> {
+    loop {
+        draw(stdout, grid, *game_over, *cursor_pos, easy)?;
+        match event::read()? {
+            CEvent::Key(key) => {
+                if !handle_key(key, grid, game_over, cursor_pos, easy) {
+                    return Ok(());
+                }
+            }
+            CEvent::Mouse(mouse) => handle_mouse(mouse, grid, game_over, cursor_pos),
+            _ => {}
+        }
+    }
+}
+
+// Returns false when the player asked to quit.
+fn handle_key(
+    key: KeyEvent,
+    grid: &mut Grid,
+    game_over: &mut GameOver,
+    cursor_pos: &mut GridPos,
+    easy: bool,
+) -> bool {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return false,
+        KeyCode::Char('r') => {
+            grid.start(grid.height(), grid.width(), grid.fraction(), grid.torus());
+            *game_over = GameOver::Ongoing;
+        }
+        KeyCode::Char('u') if *game_over == GameOver::Loss && easy => {
+            grid.clear_explosions();
+            *game_over = GameOver::Ongoing;
+        }
+        KeyCode::Up | KeyCode::Char('w') => *cursor_pos = move_cursor(grid, *cursor_pos, -1, 0),
+        KeyCode::Down | KeyCode::Char('s') => *cursor_pos = move_cursor(grid, *cursor_pos, 1, 0),
+        KeyCode::Left | KeyCode::Char('a') => *cursor_pos = move_cursor(grid, *cursor_pos, 0, -1),
+        KeyCode::Right | KeyCode::Char('d') => *cursor_pos = move_cursor(grid, *cursor_pos, 0, 1),
+        KeyCode::Char('f') if *game_over == GameOver::Ongoing => {
+            finish_move(grid.flag(*cursor_pos), grid, game_over);
+        }
+        KeyCode::Enter | KeyCode::Char(' ') if *game_over == GameOver::Ongoing => {
+            finish_move(grid.open(*cursor_pos), grid, game_over);
+        }
+        _ => {}
+    }
+    true
+}
+
+fn handle_mouse(mouse: MouseEvent, grid: &mut Grid, game_over: &mut GameOver, cursor_pos: &mut GridPos) {
+    if *game_over != GameOver::Ongoing {
+        return;
+    }
+    let row = mouse.row as usize;
+    let col = mouse.column as usize / CELL_WIDTH as usize;
+    if row >= grid.height() || col >= grid.width() {
+        return;
+    }
+    let pos = GridPos { row, col };
+    *cursor_pos = pos;
+    let exploded = match mouse.kind {
+        MouseEventKind::Down(CMouseButton::Left) => grid.flag(pos),
+        MouseEventKind::Down(CMouseButton::Right) => grid.open(pos),
+        _ => return,
+    };
+    finish_move(exploded, grid, game_over);
+}
+
+fn finish_move(exploded: bool, grid: &Grid, game_over: &mut GameOver) {
+    if exploded {
+        *game_over = GameOver::Loss;
+    } else if grid.is_win() {
+        *game_over = GameOver::Win;
+    }
+}
+
+fn move_cursor(grid: &Grid, pos: GridPos, d_row: isize, d_col: isize) -> GridPos {
+    let height = grid.height() as isize;
+    let width = grid.width() as isize;
+    let row = pos.row as isize + d_row;
+    let col = pos.col as isize + d_col;
+    let (row, col) = if grid.torus() {
+        (row.rem_euclid(height), col.rem_euclid(width))
+    } else {
+        (row.clamp(0, height - 1), col.clamp(0, width - 1))
+    };
+    GridPos {
+        row: row as usize,
+        col: col as usize,
+    }
+}
+
+fn draw(
+    stdout: &mut impl Write,
+    grid: &Grid,
+    game_over: GameOver,
+    cursor_pos: GridPos,
+    easy: bool,
+) -> io::Result<()> {
+    execute!(stdout, terminal::Clear(ClearType::All))?;
+    for cell in grid.view() {
+        let (glyph, color) = glyph_for(cell.fill, cell.number);
+        execute!(
+            stdout,
+            cursor::MoveTo(cell.pos.col as u16 * CELL_WIDTH, cell.pos.row as u16),
+            SetForegroundColor(color),
+            Print(glyph),
+            ResetColor
+        )?;
+    }
+    let status = match game_over {
+        GameOver::Ongoing => "WASD/arrows move, Enter/Space open, f flag, r restart, q quit",
+        GameOver::Win => "You win! r restart, q quit",
+        GameOver::Loss if easy => "Boom! u undo, r restart, q quit",
+        GameOver::Loss => "Boom! r restart, q quit",
+    };
+    execute!(
+        stdout,
+        cursor::MoveTo(0, grid.height() as u16 + 1),
+        Print(status),
+        cursor::MoveTo(cursor_pos.col as u16 * CELL_WIDTH, cursor_pos.row as u16)
+    )?;
+    stdout.flush()
+}
+
+fn glyph_for(fill: DisplayFill, number: Option<u8>) -> (char, CColor) {
+    match fill {
+        DisplayFill::Hidden => ('.', CColor::DarkGrey),
+        DisplayFill::Flagged => ('F', CColor::Magenta),
+        DisplayFill::Exploded => ('*', CColor::Red),
+        DisplayFill::Opened => match number {
+            Some(n) => (char::from(b'0' + n), number_color(n)),
+            None => (' ', CColor::White),
+        },
+    }
+}
+
+fn number_color(n: u8) -> CColor {
+    match n {
+        1 => CColor::Blue,
+        2 => CColor::Green,
+        3 => CColor::Red,
+        4 => CColor::White,
+        5 => CColor::Magenta,
+        6 => CColor::Cyan,
+        7 => CColor::DarkYellow,
+        _ => CColor::DarkGreen,
+    }
+}